@@ -0,0 +1,6 @@
+//! Resource limit bookkeeping shared by all per-segment aggregation collectors.
+
+/// A guard held by each aggregation's per-segment state. It carries no data: it reserves no
+/// memory budget and releases nothing when dropped.
+#[derive(Debug, Default)]
+pub(crate) struct ResourceLimitGuard;
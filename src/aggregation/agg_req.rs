@@ -0,0 +1,81 @@
+//! The aggregation request tree: user-facing aggregation definitions, before any index-specific
+//! accessor has been resolved.
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use super::bucket::{
+    DateHistogramAggregationReq, HistogramAggregation, RangeAggregation, TermsAggregation,
+};
+use super::metric::{
+    AverageAggregation, CountAggregation, MaxAggregation, MinAggregation, ModeAggregation,
+    PercentilesAggregationReq, StatsAggregation, SumAggregation,
+};
+
+/// A named tree of aggregation requests, as submitted by the caller.
+pub type Aggregations = HashMap<String, Aggregation>;
+
+/// A single aggregation request together with its sub-aggregations.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Aggregation {
+    #[serde(flatten)]
+    pub agg: AggregationVariants,
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub sub_aggregation: Aggregations,
+}
+
+impl Aggregation {
+    /// Returns this aggregation's sub-aggregation tree.
+    pub fn sub_aggregation(&self) -> &Aggregations {
+        &self.sub_aggregation
+    }
+}
+
+/// The concrete aggregation kind requested, e.g. a bucket or metric aggregation.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AggregationVariants {
+    /// Buckets documents into user-defined ranges.
+    Range(RangeAggregation),
+    /// Buckets documents into fixed-size numeric intervals.
+    Histogram(HistogramAggregation),
+    /// Buckets documents into fixed-size date/time intervals.
+    DateHistogram(DateHistogramAggregationReq),
+    /// Buckets documents by the distinct values of a field.
+    Terms(TermsAggregation),
+    /// Computes the average value of a field.
+    Average(AverageAggregation),
+    /// Counts the values of a field.
+    Count(CountAggregation),
+    /// Computes the maximum value of a field.
+    Max(MaxAggregation),
+    /// Computes the minimum value of a field.
+    Min(MinAggregation),
+    /// Computes count/sum/min/max/average statistics of a field.
+    Stats(StatsAggregation),
+    /// Computes the sum of a field's values.
+    Sum(SumAggregation),
+    /// Computes the most frequent value of a field.
+    Mode(ModeAggregation),
+    /// Interpolated (`PERCENTILE_CONT`, the default) percentiles of a field.
+    Percentiles(PercentilesAggregationReq),
+    /// Discrete (`PERCENTILE_DISC`) percentiles of a field: each result is an actual value
+    /// present in the data, rather than interpolated between two values.
+    PercentileDisc(PercentilesAggregationReq),
+}
+
+impl Default for AggregationVariants {
+    fn default() -> Self {
+        AggregationVariants::Count(CountAggregation::default())
+    }
+}
+
+impl Default for Aggregation {
+    fn default() -> Self {
+        Aggregation {
+            agg: AggregationVariants::default(),
+            sub_aggregation: Aggregations::default(),
+        }
+    }
+}
@@ -7,6 +7,7 @@ use super::agg_req::{Aggregation, AggregationVariants, Aggregations};
 use super::bucket::{
     DateHistogramAggregationReq, HistogramAggregation, RangeAggregation, TermsAggregation,
 };
+use super::metric::mode::ModeAggregation;
 use super::metric::{
     AverageAggregation, CountAggregation, MaxAggregation, MinAggregation, StatsAggregation,
     SumAggregation,
@@ -37,8 +38,10 @@ pub struct AggregationWithAccessor {
     pub(crate) accessor: Column<u64>,
     pub(crate) str_dict_column: Option<StrColumn>,
     pub(crate) field_type: ColumnType,
-    /// In case there are multiple types of fast fields, e.g. string and numeric.
-    /// Only used for term aggregations currently.
+    /// Either a second fast field for the same field name when there are multiple column
+    /// types (e.g. string and numeric, used by term and mode aggregations), or the ordering
+    /// key for `percentile_disc`'s `order_by` ("WITHIN GROUP"-style ordering key). The two uses
+    /// are mutually exclusive per aggregation.
     pub(crate) accessor2: Option<(Column<u64>, ColumnType)>,
     pub(crate) sub_aggregation: AggregationsWithAccessor,
     pub(crate) limits: ResourceLimitGuard,
@@ -86,6 +89,20 @@ impl AggregationWithAccessor {
                 accessor2 = columns.pop();
                 first
             }
+            Mode(ModeAggregation { field: field_name }) => {
+                str_dict_column = reader.fast_fields().str(field_name)?;
+                let allowed_column_types = [
+                    ColumnType::I64,
+                    ColumnType::U64,
+                    ColumnType::F64,
+                    ColumnType::Str,
+                ];
+                let mut columns =
+                    get_all_ff_reader_or_empty(reader, field_name, Some(&allowed_column_types))?;
+                let first = columns.pop().unwrap();
+                accessor2 = columns.pop();
+                first
+            }
             Average(AverageAggregation { field: field_name })
             | Count(CountAggregation { field: field_name })
             | Max(MaxAggregation { field: field_name })
@@ -97,12 +114,40 @@ impl AggregationWithAccessor {
 
                 (accessor, field_type)
             }
+            // `PERCENTILE_CONT` (the default): percentiles are approximated from a t-digest
+            // sketch built directly off the measured values, so there is no rank notion that an
+            // `order_by` column could plug into.
             Percentiles(percentiles) => {
+                if percentiles.order_by_field_name().is_some() {
+                    return Err(crate::TantivyError::InvalidArgument(format!(
+                        "`order_by` is only supported for `percentile_disc`, not the default \
+                         interpolated percentiles (field `{}`)",
+                        percentiles.field_name()
+                    )));
+                }
+                get_ff_reader(
+                    reader,
+                    percentiles.field_name(),
+                    Some(get_numeric_or_date_column_types()),
+                )?
+            }
+            // `PERCENTILE_DISC`: the result must be an actual observed value, selected via a
+            // dedicated collector and variant rather than a flag on the interpolated request. An
+            // optional `order_by` ("WITHIN GROUP"-style ordering key) ranks by a separate column
+            // while `accessor` still carries the measured values.
+            PercentileDisc(percentiles) => {
                 let (accessor, field_type) = get_ff_reader(
                     reader,
                     percentiles.field_name(),
                     Some(get_numeric_or_date_column_types()),
                 )?;
+                if let Some(order_by_field) = percentiles.order_by_field_name() {
+                    accessor2 = Some(get_ff_reader(
+                        reader,
+                        order_by_field,
+                        Some(get_numeric_or_date_column_types()),
+                    )?);
+                }
                 (accessor, field_type)
             }
         };
@@ -0,0 +1,34 @@
+//! Bucket aggregation request types.
+//!
+//! Only the shape referenced by [`super::agg_req_with_accessor`] is modeled here; bucket
+//! collection itself lives alongside the rest of the aggregation pipeline.
+
+use serde::{Deserialize, Serialize};
+
+/// Buckets documents into user-defined numeric ranges.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct RangeAggregation {
+    /// The field to bucket on.
+    pub field: String,
+}
+
+/// Buckets documents into fixed-size numeric intervals.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct HistogramAggregation {
+    /// The field to bucket on.
+    pub field: String,
+}
+
+/// Buckets documents into fixed-size date/time intervals.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct DateHistogramAggregationReq {
+    /// The field to bucket on.
+    pub field: String,
+}
+
+/// Buckets documents by the distinct values of a field.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct TermsAggregation {
+    /// The field to bucket on.
+    pub field: String,
+}
@@ -0,0 +1,66 @@
+//! Intermediate, per-segment aggregation results that merge across segments before the final
+//! response is assembled.
+
+use super::metric::{SegmentModeCollector, SegmentPercentileDiscCollector};
+
+/// One metric aggregation's intermediate (mergeable) state.
+#[derive(Clone, Debug)]
+pub(crate) enum IntermediateMetricResult {
+    /// The most frequent value of a field, see
+    /// [`super::agg_req::AggregationVariants::Mode`].
+    Mode(SegmentModeCollector),
+    /// A discrete percentile of a field, see
+    /// [`super::agg_req::AggregationVariants::PercentileDisc`].
+    PercentileDisc(SegmentPercentileDiscCollector),
+}
+
+impl IntermediateMetricResult {
+    /// Merges `other`'s state into `self`. Both sides must come from the same aggregation.
+    pub(crate) fn merge_fruits(&mut self, other: IntermediateMetricResult) {
+        match (self, other) {
+            (IntermediateMetricResult::Mode(a), IntermediateMetricResult::Mode(b)) => {
+                a.merge_fruits(&b)
+            }
+            (
+                IntermediateMetricResult::PercentileDisc(a),
+                IntermediateMetricResult::PercentileDisc(b),
+            ) => a.merge_fruits(&b),
+            (this, other) => unreachable!(
+                "merged intermediate results must come from the same aggregation, got {this:?} \
+                 and {other:?}"
+            ),
+        }
+    }
+
+    /// Resolves a `Mode` result to its final raw value.
+    ///
+    /// String fields still need resolving through `str_dict_column` at the response-assembly
+    /// step; this only returns the raw fast field value (or term ordinal).
+    ///
+    /// Panics if called on a non-`Mode` result.
+    pub(crate) fn finalize_raw(&self) -> Option<u64> {
+        match self {
+            IntermediateMetricResult::Mode(collector) => collector.finalize(),
+            IntermediateMetricResult::PercentileDisc(_) => unreachable!(
+                "PercentileDisc results are finalized per requested percentile via \
+                 `finalize_percentile`, not `finalize_raw`"
+            ),
+        }
+    }
+
+    /// Resolves a `PercentileDisc` result to its final raw value for one requested percentile
+    /// (`percentile` in `[0, 100]`).
+    ///
+    /// Returns the raw fast field value; `percentile_disc` only accepts numeric and date
+    /// columns, so there is no term ordinal to resolve.
+    ///
+    /// Panics if called on a non-`PercentileDisc` result.
+    pub(crate) fn finalize_percentile(&self, percentile: f64) -> Option<u64> {
+        match self {
+            IntermediateMetricResult::PercentileDisc(collector) => collector.finalize(percentile),
+            IntermediateMetricResult::Mode(_) => unreachable!(
+                "Mode results are finalized via `finalize_raw`, not `finalize_percentile`"
+            ),
+        }
+    }
+}
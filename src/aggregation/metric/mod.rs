@@ -0,0 +1,56 @@
+//! Metric aggregations: aggregations that compute a single value (or small, fixed-shape result)
+//! over a field, as opposed to bucket aggregations which partition documents into groups.
+
+pub(crate) mod mode;
+pub(crate) mod ordered_set;
+pub(crate) mod percentiles;
+pub(crate) mod percentiles_disc;
+
+pub use mode::ModeAggregation;
+pub use percentiles::PercentilesAggregationReq;
+pub(crate) use mode::SegmentModeCollector;
+pub(crate) use percentiles_disc::SegmentPercentileDiscCollector;
+
+use serde::{Deserialize, Serialize};
+
+/// Computes the average value of a field.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct AverageAggregation {
+    /// The field to average.
+    pub field: String,
+}
+
+/// Counts the values of a field.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct CountAggregation {
+    /// The field to count.
+    pub field: String,
+}
+
+/// Computes the maximum value of a field.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct MaxAggregation {
+    /// The field to compute the max of.
+    pub field: String,
+}
+
+/// Computes the minimum value of a field.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct MinAggregation {
+    /// The field to compute the min of.
+    pub field: String,
+}
+
+/// Computes count/sum/min/max/average statistics of a field in one pass.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct StatsAggregation {
+    /// The field to compute statistics on.
+    pub field: String,
+}
+
+/// Computes the sum of a field's values.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct SumAggregation {
+    /// The field to sum.
+    pub field: String,
+}
@@ -0,0 +1,141 @@
+//! Mode aggregation. Returns the most frequently occurring value of a field.
+
+use std::collections::HashMap;
+
+use columnar::ColumnType;
+use serde::{Deserialize, Serialize};
+
+use crate::aggregation::agg_req_with_accessor::AggregationWithAccessor;
+use crate::aggregation::intermediate_agg_result::IntermediateMetricResult;
+use crate::aggregation::segment_agg_result::SegmentMetricCollector;
+use crate::DocId;
+
+/// Finds the most frequent value of a field.
+///
+/// On a tie the smallest value wins (smallest term ordinal for string fields), so the result is
+/// deterministic regardless of segment merge order.
+///
+/// ```JSON
+/// {
+///     "mode": {
+///         "field": "character"
+///     }
+/// }
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct ModeAggregation {
+    /// The field name to compute the mode on.
+    pub field: String,
+}
+
+impl ModeAggregation {
+    /// Returns the field name the aggregation is computed on.
+    pub fn field_name(&self) -> &str {
+        &self.field
+    }
+}
+
+/// Per-segment state for the mode aggregation: counts are keyed by the raw fast field value (or
+/// term ordinal for string fields) and merged by summing the per-value counts before picking the
+/// max.
+#[derive(Clone, Debug)]
+pub(crate) struct SegmentModeCollector {
+    field_type: ColumnType,
+    entries: HashMap<u64, u64>,
+}
+
+impl SegmentModeCollector {
+    pub(crate) fn from_req(field_type: ColumnType) -> Self {
+        SegmentModeCollector {
+            field_type,
+            entries: HashMap::new(),
+        }
+    }
+
+    /// Registers `count` additional occurrences of `value` in this segment.
+    pub(crate) fn collect_value(&mut self, value: u64, count: u64) {
+        *self.entries.entry(value).or_insert(0) += count;
+    }
+
+    /// Merges another segment's state into this one.
+    pub(crate) fn merge_fruits(&mut self, other: &SegmentModeCollector) {
+        for (value, count) in &other.entries {
+            *self.entries.entry(*value).or_insert(0) += count;
+        }
+    }
+
+    /// Returns the winning raw value, breaking ties deterministically.
+    pub(crate) fn finalize(&self) -> Option<u64> {
+        self.entries
+            .iter()
+            .fold(None, |best: Option<(u64, u64)>, (&value, &count)| {
+                match best {
+                    Some((best_value, best_count))
+                        if count < best_count || (count == best_count && value >= best_value) =>
+                    {
+                        Some((best_value, best_count))
+                    }
+                    _ => Some((value, count)),
+                }
+            })
+            .map(|(value, _count)| value)
+    }
+}
+
+impl SegmentMetricCollector for SegmentModeCollector {
+    fn collect_block(
+        &mut self,
+        docs: &[DocId],
+        agg_with_accessor: &mut AggregationWithAccessor,
+    ) -> crate::Result<()> {
+        agg_with_accessor
+            .column_block_accessor
+            .fetch_block(docs, &agg_with_accessor.accessor);
+        let values: Vec<u64> = agg_with_accessor
+            .column_block_accessor
+            .iter_vals()
+            .collect();
+        for value in values {
+            self.collect_value(value, 1);
+        }
+        Ok(())
+    }
+
+    fn into_intermediate(self: Box<Self>) -> IntermediateMetricResult {
+        IntermediateMetricResult::Mode(*self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn finalize_breaks_ties_by_smallest_value() {
+        let mut collector = SegmentModeCollector::from_req(ColumnType::U64);
+        collector.collect_value(7, 3);
+        collector.collect_value(2, 3);
+        collector.collect_value(9, 1);
+        assert_eq!(collector.finalize(), Some(2));
+    }
+
+    #[test]
+    fn merge_fruits_sums_counts_across_segments() {
+        let mut a = SegmentModeCollector::from_req(ColumnType::U64);
+        a.collect_value(1, 2);
+        a.collect_value(2, 1);
+        let mut b = SegmentModeCollector::from_req(ColumnType::U64);
+        b.collect_value(2, 2);
+
+        a.merge_fruits(&b);
+
+        // `2` now has 1 + 2 = 3 occurrences, ahead of `1`'s 2.
+        assert_eq!(a.finalize(), Some(2));
+    }
+
+    #[test]
+    fn finalize_on_empty_column_is_none() {
+        let collector = SegmentModeCollector::from_req(ColumnType::U64);
+        assert_eq!(collector.finalize(), None);
+    }
+}
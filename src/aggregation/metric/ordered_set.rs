@@ -0,0 +1,36 @@
+//! Shared plumbing for ordered-set aggregations (currently just `percentile_disc`) that accept
+//! a separate `order_by` column: instead of working off the measured accessor alone, the
+//! collector zips it with the ordering accessor per doc and resolves the result from the
+//! sorted `(order_key, measured)` pairs.
+
+/// One `(order_key, measured)` pair collected for a single document.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) struct OrderedValue {
+    pub(crate) order_key: u64,
+    pub(crate) measured: u64,
+}
+
+/// Per-segment state: the raw `(order_key, measured)` pairs observed so far.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct SegmentOrderedValues {
+    pub(crate) values: Vec<OrderedValue>,
+}
+
+impl SegmentOrderedValues {
+    pub(crate) fn collect_pair(&mut self, order_key: u64, measured: u64) {
+        self.values.push(OrderedValue { order_key, measured });
+    }
+
+    /// Merges another segment's pairs into this one.
+    pub(crate) fn merge_fruits(&mut self, other: &SegmentOrderedValues) {
+        self.values.extend_from_slice(&other.values);
+    }
+
+    /// Returns the collected pairs sorted ascending by `order_key`, ties broken by `measured`
+    /// so that walking the result in order is deterministic.
+    pub(crate) fn sorted(&self) -> Vec<OrderedValue> {
+        let mut values = self.values.clone();
+        values.sort_by_key(|pair| (pair.order_key, pair.measured));
+        values
+    }
+}
@@ -0,0 +1,58 @@
+//! Percentile request definition, shared by the interpolated (`PERCENTILE_CONT`, the default)
+//! and discrete (`PERCENTILE_DISC`) percentile aggregations; only the collector each one selects
+//! differs.
+
+use serde::{Deserialize, Serialize};
+
+/// Computes one or more percentiles of a field.
+///
+/// ```JSON
+/// {
+///     "percentiles": {
+///         "field": "load_time",
+///         "percents": [50.0, 95.0, 99.0]
+///     }
+/// }
+/// ```
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct PercentilesAggregationReq {
+    /// The field to compute percentiles on.
+    pub field: String,
+    /// The percentiles to compute, e.g. `[50.0, 95.0, 99.0]`.
+    #[serde(default = "default_percentiles")]
+    pub percents: Vec<f64>,
+    /// An optional `WITHIN GROUP`-style ordering key: ranks the field's values by a separate
+    /// column instead of by their own value.
+    ///
+    /// Only supported for `percentile_disc`; rejected on the default interpolated percentiles,
+    /// since there's no well-defined way to interpolate between two values ranked by an unrelated
+    /// key.
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub order_by: Option<String>,
+}
+
+impl Default for PercentilesAggregationReq {
+    fn default() -> Self {
+        Self {
+            field: String::new(),
+            percents: default_percentiles(),
+            order_by: None,
+        }
+    }
+}
+
+fn default_percentiles() -> Vec<f64> {
+    vec![1.0, 5.0, 25.0, 50.0, 75.0, 95.0, 99.0]
+}
+
+impl PercentilesAggregationReq {
+    /// Returns the field name the aggregation is computed on.
+    pub fn field_name(&self) -> &str {
+        &self.field
+    }
+
+    /// Returns the ordering field name, if a separate one was requested.
+    pub fn order_by_field_name(&self) -> Option<&str> {
+        self.order_by.as_deref()
+    }
+}
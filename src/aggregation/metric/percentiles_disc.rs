@@ -0,0 +1,232 @@
+//! Discrete percentile aggregation (`PERCENTILE_DISC`).
+//!
+//! Unlike the default interpolated percentiles path, which approximates `PERCENTILE_CONT` via a
+//! t-digest sketch, this returns an actual value present in the data: for a requested fraction
+//! `p`, the smallest value `x` such that the cumulative fraction of values `<= x` is `>= p`.
+//!
+//! An optional `order_by` ("WITHIN GROUP"-style ordering key) ranks by a separate column instead
+//! of the measured values themselves: the rank position is resolved by walking the values in
+//! ascending `order_by` order, and the measured value at that position is returned.
+
+use std::collections::BTreeMap;
+
+use super::ordered_set::SegmentOrderedValues;
+use crate::aggregation::agg_req_with_accessor::AggregationWithAccessor;
+use crate::aggregation::intermediate_agg_result::IntermediateMetricResult;
+use crate::aggregation::segment_agg_result::SegmentMetricCollector;
+use crate::DocId;
+
+/// Per-segment state for a discrete percentile aggregation.
+#[derive(Clone, Debug)]
+pub(crate) enum SegmentPercentileDiscCollector {
+    /// No `order_by`: values are accumulated into a sorted map of value -> count, which merges
+    /// cheaply by summing counts across segments.
+    ByValue { counts: BTreeMap<u64, u64> },
+    /// `order_by` set: the rank is resolved against the ordering accessor, and the measured
+    /// value paired with it is returned.
+    ByOrderKey { ordered: SegmentOrderedValues },
+}
+
+impl SegmentPercentileDiscCollector {
+    pub(crate) fn from_req(has_order_by: bool) -> Self {
+        if has_order_by {
+            SegmentPercentileDiscCollector::ByOrderKey {
+                ordered: SegmentOrderedValues::default(),
+            }
+        } else {
+            SegmentPercentileDiscCollector::ByValue {
+                counts: BTreeMap::new(),
+            }
+        }
+    }
+
+    /// Registers one occurrence of `value` in this segment (no `order_by`).
+    pub(crate) fn collect_value(&mut self, value: u64) {
+        match self {
+            SegmentPercentileDiscCollector::ByValue { counts } => {
+                *counts.entry(value).or_insert(0) += 1;
+            }
+            SegmentPercentileDiscCollector::ByOrderKey { .. } => {
+                debug_assert!(
+                    false,
+                    "collect_value called on an order_by percentile_disc collector"
+                );
+            }
+        }
+    }
+
+    /// Registers one occurrence of `measured` ranked under `order_key` (`order_by` set).
+    pub(crate) fn collect_pair(&mut self, order_key: u64, measured: u64) {
+        match self {
+            SegmentPercentileDiscCollector::ByOrderKey { ordered } => {
+                ordered.collect_pair(order_key, measured);
+            }
+            SegmentPercentileDiscCollector::ByValue { .. } => {
+                debug_assert!(
+                    false,
+                    "collect_pair called on a by-value percentile_disc collector"
+                );
+            }
+        }
+    }
+
+    /// Merges another segment's state into this one. Both sides must be in the same mode.
+    pub(crate) fn merge_fruits(&mut self, other: &SegmentPercentileDiscCollector) {
+        match (self, other) {
+            (
+                SegmentPercentileDiscCollector::ByValue { counts },
+                SegmentPercentileDiscCollector::ByValue {
+                    counts: other_counts,
+                },
+            ) => {
+                for (value, count) in other_counts {
+                    *counts.entry(*value).or_insert(0) += count;
+                }
+            }
+            (
+                SegmentPercentileDiscCollector::ByOrderKey { ordered },
+                SegmentPercentileDiscCollector::ByOrderKey {
+                    ordered: other_ordered,
+                },
+            ) => {
+                ordered.merge_fruits(other_ordered);
+            }
+            _ => unreachable!(
+                "percentile_disc collectors for the same aggregation always share a mode"
+            ),
+        }
+    }
+
+    /// Resolves the discrete percentile for fraction `percentile` in `[0, 100]`.
+    ///
+    /// Returns `None` if no values were collected.
+    pub(crate) fn finalize(&self, percentile: f64) -> Option<u64> {
+        let target_fraction = (percentile / 100.0).clamp(0.0, 1.0);
+        match self {
+            SegmentPercentileDiscCollector::ByValue { counts } => {
+                let total: u64 = counts.values().sum();
+                if total == 0 {
+                    return None;
+                }
+                let mut cumulative = 0u64;
+                for (&value, &count) in counts {
+                    cumulative += count;
+                    if cumulative as f64 / total as f64 >= target_fraction {
+                        return Some(value);
+                    }
+                }
+                // Floating point rounding at the tail can leave the loop above without a match;
+                // the largest observed value always satisfies `p <= 1.0`.
+                counts.keys().next_back().copied()
+            }
+            SegmentPercentileDiscCollector::ByOrderKey { ordered } => {
+                let sorted = ordered.sorted();
+                let total = sorted.len();
+                if total == 0 {
+                    return None;
+                }
+                let mut cumulative = 0u64;
+                for pair in &sorted {
+                    cumulative += 1;
+                    if cumulative as f64 / total as f64 >= target_fraction {
+                        return Some(pair.measured);
+                    }
+                }
+                sorted.last().map(|pair| pair.measured)
+            }
+        }
+    }
+}
+
+impl SegmentMetricCollector for SegmentPercentileDiscCollector {
+    fn collect_block(
+        &mut self,
+        docs: &[DocId],
+        agg_with_accessor: &mut AggregationWithAccessor,
+    ) -> crate::Result<()> {
+        match self {
+            SegmentPercentileDiscCollector::ByValue { .. } => {
+                agg_with_accessor
+                    .column_block_accessor
+                    .fetch_block(docs, &agg_with_accessor.accessor);
+                let values: Vec<u64> = agg_with_accessor
+                    .column_block_accessor
+                    .iter_vals()
+                    .collect();
+                for value in values {
+                    self.collect_value(value);
+                }
+            }
+            SegmentPercentileDiscCollector::ByOrderKey { .. } => {
+                let (order_accessor, _) = agg_with_accessor
+                    .accessor2
+                    .as_ref()
+                    .expect("order_by accessor missing for an order_by percentile_disc aggregation");
+                // Pairs are built per doc from each column's first value; a doc missing either
+                // value is skipped.
+                for &doc in docs {
+                    let measured = agg_with_accessor.accessor.first(doc);
+                    if let (Some(order_key), Some(measured)) = (order_accessor.first(doc), measured)
+                    {
+                        self.collect_pair(order_key, measured);
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn into_intermediate(self: Box<Self>) -> IntermediateMetricResult {
+        IntermediateMetricResult::PercentileDisc(*self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn finalize_picks_smallest_value_reaching_the_target_fraction() {
+        let mut collector = SegmentPercentileDiscCollector::from_req(false);
+        for value in 1..=10u64 {
+            collector.collect_value(value);
+        }
+        // The 50th percentile of 10 equally-weighted values is the 5th smallest: 5.
+        assert_eq!(collector.finalize(50.0), Some(5));
+        assert_eq!(collector.finalize(100.0), Some(10));
+    }
+
+    #[test]
+    fn merge_fruits_sums_counts_across_segments() {
+        let mut a = SegmentPercentileDiscCollector::from_req(false);
+        a.collect_value(1);
+        a.collect_value(1);
+        let mut b = SegmentPercentileDiscCollector::from_req(false);
+        b.collect_value(2);
+
+        a.merge_fruits(&b);
+
+        // Three values total: [1, 1, 2]; the 50th percentile lands on the second entry, 1.
+        assert_eq!(a.finalize(50.0), Some(1));
+    }
+
+    #[test]
+    fn finalize_on_empty_column_is_none() {
+        let collector = SegmentPercentileDiscCollector::from_req(false);
+        assert_eq!(collector.finalize(50.0), None);
+    }
+
+    #[test]
+    fn order_by_ranks_by_the_ordering_accessor_not_the_measured_value() {
+        let mut collector = SegmentPercentileDiscCollector::from_req(true);
+        // Ranked ascending by order_key: (order_key=1, measured=10), (order_key=2, measured=30),
+        // (order_key=3, measured=20).
+        collector.collect_pair(1, 10);
+        collector.collect_pair(3, 20);
+        collector.collect_pair(2, 30);
+
+        // The 50th percentile by rank position is the 2nd of 3, i.e. measured=30, even though the
+        // median of the measured values by value is 20.
+        assert_eq!(collector.finalize(50.0), Some(30));
+    }
+}
@@ -0,0 +1,57 @@
+//! Aggregation pipeline: request parsing, per-segment accessor/collector wiring, and
+//! segment-to-final result merging.
+
+mod agg_limits;
+pub mod agg_req;
+pub(crate) mod agg_req_with_accessor;
+mod bucket;
+pub(crate) mod intermediate_agg_result;
+pub mod metric;
+pub(crate) mod segment_agg_result;
+
+/// A name-preserving, insertion-ordered collection used throughout the aggregation pipeline so
+/// results can be reported back under the same keys the request used.
+#[derive(Clone, Debug)]
+pub(crate) struct VecWithNames<T> {
+    keys: Vec<String>,
+    values: Vec<T>,
+}
+
+impl<T> Default for VecWithNames<T> {
+    fn default() -> Self {
+        Self {
+            keys: Vec::new(),
+            values: Vec::new(),
+        }
+    }
+}
+
+impl<T> VecWithNames<T> {
+    pub(crate) fn from_entries(entries: Vec<(String, T)>) -> Self {
+        let mut keys = Vec::with_capacity(entries.len());
+        let mut values = Vec::with_capacity(entries.len());
+        for (key, value) in entries {
+            keys.push(key);
+            values.push(value);
+        }
+        Self { keys, values }
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    pub(crate) fn iter(&self) -> impl Iterator<Item = (&str, &T)> {
+        self.keys
+            .iter()
+            .map(String::as_str)
+            .zip(self.values.iter())
+    }
+
+    pub(crate) fn iter_mut(&mut self) -> impl Iterator<Item = (&str, &mut T)> {
+        self.keys
+            .iter()
+            .map(String::as_str)
+            .zip(self.values.iter_mut())
+    }
+}
@@ -0,0 +1,60 @@
+//! Per-segment aggregation collection.
+//!
+//! Each metric aggregation in the request tree gets a [`SegmentMetricCollector`] that observes
+//! matching documents via `collect_block` and is finally converted into an
+//! [`IntermediateMetricResult`] for cross-segment merging.
+
+use super::agg_limits::ResourceLimitGuard;
+use super::agg_req::AggregationVariants;
+use super::agg_req_with_accessor::AggregationWithAccessor;
+use super::intermediate_agg_result::IntermediateMetricResult;
+use super::metric::{SegmentModeCollector, SegmentPercentileDiscCollector};
+use crate::DocId;
+
+/// Caps on the memory an aggregation request may use. Bucket counts are not capped.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct AggregationLimits {
+    memory_limit_bytes: Option<u64>,
+}
+
+impl AggregationLimits {
+    pub(crate) fn new_guard(&self) -> ResourceLimitGuard {
+        ResourceLimitGuard::default()
+    }
+}
+
+/// A per-segment collector for a single metric aggregation.
+pub(crate) trait SegmentMetricCollector: std::fmt::Debug {
+    /// Observes `docs`, pulling values through `agg_with_accessor`'s accessor(s).
+    fn collect_block(
+        &mut self,
+        docs: &[DocId],
+        agg_with_accessor: &mut AggregationWithAccessor,
+    ) -> crate::Result<()>;
+
+    /// Converts this segment's state into a mergeable intermediate result.
+    fn into_intermediate(self: Box<Self>) -> IntermediateMetricResult;
+}
+
+/// Builds the segment-local collector for a single metric aggregation.
+///
+/// Bucket aggregations (`Range`, `Histogram`, `DateHistogram`, `Terms`) are collected by the
+/// existing bucket collection path, not through this trait.
+pub(crate) fn build_segment_metric_collector(
+    agg_with_accessor: &AggregationWithAccessor,
+) -> crate::Result<Box<dyn SegmentMetricCollector>> {
+    match &agg_with_accessor.agg.agg {
+        AggregationVariants::Mode(_) => Ok(Box::new(SegmentModeCollector::from_req(
+            agg_with_accessor.field_type,
+        ))),
+        AggregationVariants::PercentileDisc(percentiles) => {
+            Ok(Box::new(SegmentPercentileDiscCollector::from_req(
+                percentiles.order_by_field_name().is_some(),
+            )))
+        }
+        other => unimplemented!(
+            "segment collector construction for {other:?} is handled by the existing \
+             average/count/max/min/stats/sum/percentiles collectors"
+        ),
+    }
+}